@@ -43,5 +43,41 @@ fn main() -> Result<()> {
         eprintln!("Removed incorrectly nested directory: {}", double_nested.display());
     }
 
+    // `create_exception!`-based classes are invisible to `pyo3_stub_gen`, so hand-write
+    // `exceptions.pyi` from the same `exception_stub_entries` list that drives the runtime
+    // `register_exceptions`, to keep the stub from drifting out of sync with it.
+    let exceptions_stub = output_path.join("aerospike_async").join("exceptions.pyi");
+    if let Some(parent) = exceptions_stub.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&exceptions_stub, render_exceptions_stub())?;
+    eprintln!("Wrote {}", exceptions_stub.display());
+
     Ok(())
 }
+
+/// Render `exceptions.pyi`'s contents: `AerospikeError(Exception)` followed by every other
+/// entry in `exception_stub_entries` as an `AerospikeError` subclass, each with its declared
+/// extra attributes typed `int`/`bool`/`str` by name (the only types any of them use today).
+fn render_exceptions_stub() -> String {
+    let mut out = String::from("# Auto-generated by stub_gen. Do not edit by hand.\n\n");
+    for (name, attrs) in _aerospike_async_native::exception_stub_entries() {
+        let base = if name == "AerospikeError" { "Exception" } else { "AerospikeError" };
+        out.push_str(&format!("class {}({}):\n", name, base));
+        if attrs.is_empty() {
+            out.push_str("    ...\n\n");
+            continue;
+        }
+        for attr in attrs {
+            let ty = match *attr {
+                "result_code" => "int",
+                "in_doubt" | "client_side" | "is_retryable" => "bool",
+                _ => "str",
+            };
+            out.push_str(&format!("    {}: {}\n", attr, ty));
+        }
+        out.push('\n');
+    }
+    out.push_str("def is_retryable(exc: Exception) -> bool: ...\n");
+    out
+}